@@ -5,6 +5,8 @@ mod commenting_out_guardrails;
 mod error;
 mod introspection;
 mod introspection_helpers;
+mod offline;
+mod pooled;
 mod prisma_1_defaults;
 mod re_introspection;
 mod sanitize_datamodel_names;
@@ -27,6 +29,9 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// Additional schema names to describe and merge in, for multi-schema introspection. Empty
+    /// for the common single-schema case.
+    additional_schemas: Vec<String>,
 }
 
 impl fmt::Debug for SqlIntrospectionConnector {
@@ -54,6 +59,70 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             connection_info,
             describer,
+            additional_schemas: Vec::new(),
+        })
+    }
+
+    /// Like [`SqlIntrospectionConnector::new`], but introspects several Postgres schemas instead
+    /// of just the one named in the connection string, merging the results into a single
+    /// [`SqlSchema`]. Shared enums and sequences are deduplicated across schemas, and a table
+    /// whose name collides with one already merged in is disambiguated by prefixing it with its
+    /// schema name (see [`merge_schema_into`]).
+    ///
+    /// [`Self::describe`] describes the primary schema and every name in `schema_names`
+    /// concurrently, so this opens a connection pool sized to match (see [`Self::with_pool`])
+    /// rather than a single connection that would serialize them.
+    ///
+    /// This does not mark which schema a merged-in table came from (no `@@schema(...)`-equivalent
+    /// field exists on [`sql_schema_describer::Table`] to set), and it does not resolve foreign
+    /// keys that cross a schema boundary — each schema is described independently, so a foreign
+    /// key can only be remapped against tables merged in from the *same* schema it came from. A
+    /// cross-schema foreign key will point at the wrong table index after merging.
+    pub async fn new_multi_schema(url: &str, schema_names: Vec<String>) -> ConnectorResult<SqlIntrospectionConnector> {
+        let max_connections = schema_names.len() + 1;
+        let mut connector = Self::with_pool(url, max_connections).await?;
+        connector.additional_schemas = schema_names;
+        Ok(connector)
+    }
+
+    /// Build a connector that introspects a schema snapshot captured ahead of time, rather than a
+    /// live database. `schema_json` is the JSON serialization of a [`SqlSchema`] (the same format
+    /// produced by [`IntrospectionConnector::get_database_description`]). Useful when the source database isn't
+    /// reachable from the environment running introspection.
+    pub fn new_offline(
+        schema_json: &str,
+        connection_info: ConnectionInfo,
+    ) -> SqlIntrospectionResult<SqlIntrospectionConnector> {
+        let schema: SqlSchema = serde_json::from_str(schema_json)?;
+
+        Ok(SqlIntrospectionConnector {
+            connection_info,
+            describer: Box::new(offline::OfflineDescriber::new(schema)),
+            additional_schemas: Vec::new(),
+        })
+    }
+
+    /// Like [`SqlIntrospectionConnector::new`], but the describer fans its work out across a pool
+    /// of up to `max_connections` connections instead of a single one, bounded by a
+    /// [`tokio::sync::Semaphore`]. Useful when describing several schemas concurrently (see
+    /// [`Self::new_multi_schema`], [`Self::describe`]), where a single connection would otherwise
+    /// serialize them.
+    pub async fn with_pool(url: &str, max_connections: usize) -> ConnectorResult<SqlIntrospectionConnector> {
+        let (pooled_describer, connection_info) = pooled::PooledDescriber::new(url, max_connections)
+            .instrument(tracing::debug_span!("Loading pooled describer"))
+            .await
+            .map_err(|error| {
+                ConnectionInfo::from_url(url)
+                    .map(|connection_info| error.into_connector_error(&connection_info))
+                    .unwrap_or_else(ConnectorError::url_parse_error)
+            })?;
+
+        tracing::debug!("SqlIntrospectionConnector initialized with a connection pool.");
+
+        Ok(SqlIntrospectionConnector {
+            connection_info,
+            describer: Box::new(pooled_describer),
+            additional_schemas: Vec::new(),
         })
     }
 
@@ -77,7 +146,22 @@ impl SqlIntrospectionConnector {
 
     /// Exported for tests
     pub async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        // Describe the primary schema and every additional one concurrently, rather than one at a
+        // time — with a pooled describer (see `with_pool`), this is what actually lets multi-schema
+        // introspection benefit from more than one connection.
+        let additional = futures::future::try_join_all(
+            self.additional_schemas
+                .iter()
+                .map(|schema_name| self.describer.describe(schema_name)),
+        );
+        let (mut schema, others) =
+            futures::future::try_join(self.describer.describe(self.connection_info.schema_name()), additional).await?;
+
+        for (schema_name, other) in self.additional_schemas.iter().zip(others) {
+            merge_schema_into(&mut schema, schema_name, other);
+        }
+
+        Ok(schema)
     }
 
     async fn version(&self) -> SqlIntrospectionResult<String> {
@@ -126,6 +210,47 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
     }
 }
 
+/// Fold `other` (described from `other_schema_name`)'s tables, enums and sequences into `target`,
+/// for multi-schema introspection. `other`'s foreign keys reference its own tables by index into
+/// `other.tables`; once those tables are appended after `target`'s, every such index has to shift
+/// by `target.tables.len()` (the offset the tables are about to move by), or a foreign key would
+/// end up pointing at whichever table now happens to sit at its old index in the merged schema.
+/// Enums and sequences that are defined identically in more than one schema are deduplicated with
+/// [`Dedup`] rather than rendered once per schema.
+///
+/// Two caveats this can't fully resolve from here:
+/// - A table whose name already exists in `target` (e.g. the same table name used in two
+///   different Postgres schemas) is renamed to `{other_schema_name}_{table_name}` so the merged
+///   schema doesn't end up with two same-named models, which would be invalid PSL. This loses the
+///   original name rather than namespacing it properly.
+/// - The index shift above only accounts for foreign keys from `other` into `other`'s own tables.
+///   A foreign key that crosses a schema boundary (references a table described under a different
+///   schema name) isn't detectable as such here and will end up pointing at the wrong table.
+///   Properly representing either case needs a schema-name field on
+///   [`sql_schema_describer::Table`] that isn't available to set from here yet.
+fn merge_schema_into(target: &mut SqlSchema, other_schema_name: &str, mut other: SqlSchema) {
+    let table_offset = target.tables.len();
+    let existing_names: std::collections::HashSet<String> =
+        target.tables.iter().map(|table| table.name.clone()).collect();
+
+    for table in &mut other.tables {
+        for foreign_key in &mut table.foreign_keys {
+            foreign_key.referenced_table += table_offset;
+        }
+
+        if existing_names.contains(&table.name) {
+            table.name = format!("{other_schema_name}_{}", table.name);
+        }
+    }
+
+    target.tables.extend(other.tables);
+    target.enums.extend(other.enums);
+    target.sequences.extend(other.sequences);
+
+    target.enums.clear_duplicates();
+    target.sequences.clear_duplicates();
+}
+
 trait Dedup<T: PartialEq + Clone> {
     fn clear_duplicates(&mut self);
 }
@@ -0,0 +1,36 @@
+use sql_schema_describer::{DescriberResult, SqlMetadata, SqlSchema, SqlSchemaDescriberBackend};
+
+/// A [`SqlSchemaDescriberBackend`] that replays a schema captured ahead of time instead of
+/// querying a live database. Backs [`crate::SqlIntrospectionConnector::new_offline`], for
+/// introspecting from a schema snapshot when no database connection is available.
+pub(crate) struct OfflineDescriber {
+    schema: SqlSchema,
+}
+
+impl OfflineDescriber {
+    pub(crate) fn new(schema: SqlSchema) -> Self {
+        OfflineDescriber { schema }
+    }
+}
+
+#[async_trait::async_trait]
+impl SqlSchemaDescriberBackend for OfflineDescriber {
+    async fn list_databases(&self) -> DescriberResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_metadata(&self, _schema: &str) -> DescriberResult<SqlMetadata> {
+        Ok(SqlMetadata {
+            table_count: self.schema.tables.len(),
+            size_in_bytes: 0,
+        })
+    }
+
+    async fn describe(&self, _schema: &str) -> DescriberResult<SqlSchema> {
+        Ok(self.schema.clone())
+    }
+
+    async fn version(&self, _schema: &str) -> DescriberResult<Option<String>> {
+        Ok(None)
+    }
+}
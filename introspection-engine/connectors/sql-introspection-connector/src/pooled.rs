@@ -0,0 +1,77 @@
+use crate::{schema_describer_loading, SqlError};
+use quaint::prelude::ConnectionInfo;
+use sql_schema_describer::{DescriberResult, SqlMetadata, SqlSchema, SqlSchemaDescriberBackend};
+use tokio::sync::{Mutex, Semaphore};
+
+/// A [`SqlSchemaDescriberBackend`] that fans describe work out across a bounded number of
+/// connections, instead of serializing everything on one. Each call to [`Self::describe`]
+/// acquires a permit from a [`Semaphore`] sized to `max_connections`, then claims whichever
+/// connection that permit just freed up from `free_indexes` — so describing several schemas
+/// concurrently (see multi-schema introspection) no longer has to wait for each one in turn, and
+/// two concurrent calls can never land on the same connection while another sits idle. Backs
+/// [`crate::SqlIntrospectionConnector::with_pool`].
+pub(crate) struct PooledDescriber {
+    describers: Vec<Box<dyn SqlSchemaDescriberBackend>>,
+    semaphore: Semaphore,
+    // Indexes into `describers` that aren't currently in use. Always has as many entries as there
+    // are unused permits, so a successful `acquire` is guaranteed to find one to pop.
+    free_indexes: Mutex<Vec<usize>>,
+}
+
+impl PooledDescriber {
+    pub(crate) async fn new(url: &str, max_connections: usize) -> Result<(Self, ConnectionInfo), SqlError> {
+        let max_connections = max_connections.max(1);
+        let mut describers = Vec::with_capacity(max_connections);
+        let mut connection_info = None;
+
+        for _ in 0..max_connections {
+            let (describer, info) = schema_describer_loading::load_describer(url).await?;
+            describers.push(describer);
+            connection_info.get_or_insert(info);
+        }
+
+        let pool = PooledDescriber {
+            semaphore: Semaphore::new(max_connections),
+            free_indexes: Mutex::new((0..describers.len()).collect()),
+            describers,
+        };
+
+        Ok((pool, connection_info.unwrap()))
+    }
+}
+
+#[async_trait::async_trait]
+impl SqlSchemaDescriberBackend for PooledDescriber {
+    async fn list_databases(&self) -> DescriberResult<Vec<String>> {
+        self.describers[0].list_databases().await
+    }
+
+    async fn get_metadata(&self, schema: &str) -> DescriberResult<SqlMetadata> {
+        self.describers[0].get_metadata(schema).await
+    }
+
+    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+        // If every connection in the pool is already busy, new callers queue on the semaphore
+        // rather than piling up on a single connection. The semaphore is never closed, so this
+        // only ever waits for a connection to free up — it does not fail.
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("the describer pool semaphore is never closed");
+
+        // The permit guarantees a free index is waiting to be popped: `free_indexes` always holds
+        // exactly as many entries as there are unused permits.
+        let index = self.free_indexes.lock().await.pop().expect("a permit guarantees a free index");
+
+        let result = self.describers[index].describe(schema).await;
+
+        self.free_indexes.lock().await.push(index);
+
+        result
+    }
+
+    async fn version(&self, schema: &str) -> DescriberResult<Option<String>> {
+        self.describers[0].version(schema).await
+    }
+}
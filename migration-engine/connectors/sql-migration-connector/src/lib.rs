@@ -0,0 +1,2 @@
+mod sql_schema_differ;
+pub mod statement_splitter;
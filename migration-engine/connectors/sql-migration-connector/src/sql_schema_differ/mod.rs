@@ -0,0 +1 @@
+mod differ_database;
@@ -0,0 +1,243 @@
+//! Splits a raw, hand-written SQL script into individual statements so they can be executed one
+//! by one against a connector, the same way a computed migration diff is.
+
+/// Split `script` into trimmed, non-empty statements, in order.
+///
+/// Comments (`-- ...` to end of line, and `/* ... */` blocks), single- and double-quoted string
+/// literals, backtick-quoted identifiers (MySQL), and Postgres dollar-quoted bodies (e.g.
+/// `$tag$ ... $tag$`) are all tracked in a single pass, so a `;` or a `--`/`/*` sequence that
+/// appears inside one of those constructs is not mistaken for a statement separator or a real
+/// comment. A trailing statement with no terminating semicolon is still emitted.
+pub fn split_statements(script: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = script.char_indices().peekable();
+
+    #[derive(PartialEq)]
+    enum State {
+        Normal,
+        SingleQuoted,
+        DoubleQuoted,
+        Backtick,
+        DollarQuoted,
+    }
+
+    let mut state = State::Normal;
+    let mut dollar_tag = String::new();
+
+    while let Some((idx, ch)) = chars.next() {
+        match state {
+            State::Normal => match ch {
+                '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                    chars.next();
+                    for (_, c) in chars.by_ref() {
+                        if c == '\n' {
+                            current.push('\n');
+                            break;
+                        }
+                    }
+                }
+                '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                    chars.next();
+                    let mut previous = ' ';
+                    for (_, c) in chars.by_ref() {
+                        if previous == '*' && c == '/' {
+                            break;
+                        }
+                        previous = c;
+                    }
+                }
+                '\'' => {
+                    state = State::SingleQuoted;
+                    current.push(ch);
+                }
+                '"' => {
+                    state = State::DoubleQuoted;
+                    current.push(ch);
+                }
+                '`' => {
+                    state = State::Backtick;
+                    current.push(ch);
+                }
+                '$' => {
+                    if let Some(tag) = try_match_dollar_tag(script, idx) {
+                        state = State::DollarQuoted;
+                        dollar_tag = tag.clone();
+                        current.push_str(&tag);
+
+                        for _ in 0..tag.chars().count() - 1 {
+                            chars.next();
+                        }
+                    } else {
+                        current.push(ch);
+                    }
+                }
+                ';' => {
+                    let trimmed = current.trim();
+                    if !trimmed.is_empty() {
+                        statements.push(trimmed.to_owned());
+                    }
+                    current.clear();
+                }
+                _ => current.push(ch),
+            },
+            State::SingleQuoted => {
+                current.push(ch);
+                if ch == '\'' {
+                    if matches!(chars.peek(), Some((_, '\''))) {
+                        let (_, escaped_quote) = chars.next().unwrap();
+                        current.push(escaped_quote);
+                    } else {
+                        state = State::Normal;
+                    }
+                } else if ch == '\\' {
+                    if let Some((_, next)) = chars.next() {
+                        current.push(next);
+                    }
+                }
+            }
+            State::DoubleQuoted => {
+                current.push(ch);
+                if ch == '"' {
+                    if matches!(chars.peek(), Some((_, '"'))) {
+                        let (_, escaped_quote) = chars.next().unwrap();
+                        current.push(escaped_quote);
+                    } else {
+                        state = State::Normal;
+                    }
+                } else if ch == '\\' {
+                    if let Some((_, next)) = chars.next() {
+                        current.push(next);
+                    }
+                }
+            }
+            State::Backtick => {
+                current.push(ch);
+                if ch == '`' {
+                    state = State::Normal;
+                }
+            }
+            State::DollarQuoted => {
+                if ch == '$' {
+                    if let Some(tag) = try_match_dollar_tag(script, idx) {
+                        if tag == dollar_tag {
+                            current.push_str(&tag);
+                            for _ in 0..tag.chars().count() - 1 {
+                                chars.next();
+                            }
+                            state = State::Normal;
+                            continue;
+                        }
+                    }
+                }
+                current.push(ch);
+            }
+        }
+    }
+
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        statements.push(trimmed.to_owned());
+    }
+
+    statements
+}
+
+/// If `input[start..]` begins with a dollar-quote tag (`$tag$` or `$$`), return the full tag
+/// (including both `$` delimiters).
+fn try_match_dollar_tag(input: &str, start: usize) -> Option<String> {
+    let rest = &input[start..];
+    let mut chars = rest.char_indices();
+    let (_, first) = chars.next()?;
+    debug_assert_eq!(first, '$');
+
+    for (idx, ch) in chars {
+        match ch {
+            '$' => return Some(rest[..idx + 1].to_owned()),
+            c if c.is_alphanumeric() || c == '_' => continue,
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_statements;
+
+    #[test]
+    fn splits_simple_statements() {
+        let script = "CREATE TABLE a (id INT); CREATE TABLE b (id INT)";
+        assert_eq!(
+            split_statements(script),
+            vec!["CREATE TABLE a (id INT)", "CREATE TABLE b (id INT)"]
+        );
+    }
+
+    #[test]
+    fn trailing_statement_without_semicolon_is_emitted() {
+        let script = "CREATE TABLE a (id INT);\nCREATE TABLE b (id INT)";
+        assert_eq!(
+            split_statements(script),
+            vec!["CREATE TABLE a (id INT)", "CREATE TABLE b (id INT)"]
+        );
+    }
+
+    #[test]
+    fn line_comments_are_stripped() {
+        let script = "CREATE TABLE a (id INT); -- a trailing comment\nCREATE TABLE b (id INT)";
+        assert_eq!(
+            split_statements(script),
+            vec!["CREATE TABLE a (id INT)", "CREATE TABLE b (id INT)"]
+        );
+    }
+
+    #[test]
+    fn block_comments_are_stripped() {
+        let script = "CREATE TABLE a (id INT); /* multi\nline */ CREATE TABLE b (id INT)";
+        assert_eq!(
+            split_statements(script),
+            vec!["CREATE TABLE a (id INT)", "CREATE TABLE b (id INT)"]
+        );
+    }
+
+    #[test]
+    fn semicolons_inside_single_quoted_strings_are_not_separators() {
+        let script = "INSERT INTO t VALUES ('a; b')";
+        assert_eq!(split_statements(script), vec!["INSERT INTO t VALUES ('a; b')"]);
+    }
+
+    #[test]
+    fn comment_like_sequences_inside_single_quoted_strings_are_preserved() {
+        let script = "INSERT INTO t VALUES ('a -- b; c')";
+        assert_eq!(split_statements(script), vec!["INSERT INTO t VALUES ('a -- b; c')"]);
+    }
+
+    #[test]
+    fn block_comment_like_sequences_inside_single_quoted_strings_are_preserved() {
+        let script = "INSERT INTO t VALUES ('a /* b */ c')";
+        assert_eq!(split_statements(script), vec!["INSERT INTO t VALUES ('a /* b */ c')"]);
+    }
+
+    #[test]
+    fn escaped_quotes_inside_single_quoted_strings_do_not_end_the_string() {
+        let script = "INSERT INTO t VALUES ('it''s; fine')";
+        assert_eq!(split_statements(script), vec!["INSERT INTO t VALUES ('it''s; fine')"]);
+    }
+
+    #[test]
+    fn semicolons_inside_backtick_identifiers_are_not_separators() {
+        let script = "SELECT * FROM `weird;table`";
+        assert_eq!(split_statements(script), vec!["SELECT * FROM `weird;table`"]);
+    }
+
+    #[test]
+    fn semicolons_inside_dollar_quoted_bodies_are_not_separators() {
+        let script = "CREATE FUNCTION f() RETURNS void AS $$ BEGIN SELECT 1; END; $$ LANGUAGE plpgsql";
+        assert_eq!(
+            split_statements(script),
+            vec!["CREATE FUNCTION f() RETURNS void AS $$ BEGIN SELECT 1; END; $$ LANGUAGE plpgsql"]
+        );
+    }
+}
@@ -9,16 +9,61 @@ use crate::{ApplyMigrations, CreateMigration, DiagnoseMigrationHistory, Reset, S
 use migration_core::GenericApi;
 use quaint::{prelude::Queryable, single::Quaint};
 use sql_migration_connector::SqlMigrationConnector;
-use std::future::Future;
+use std::{fmt::Display, future::Future, time::Duration};
 use tempfile::TempDir;
 use test_setup::TestApiArgs;
 
+/// Initial backoff interval for [`retry_with_backoff`]. `TestApiArgs` has no connect-tuning
+/// knobs to read this from (that would be a `test_setup` change, and that crate's source isn't
+/// part of this tree), so it's a local constant rather than something the environment can extend.
+const CONNECT_INITIAL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Max total time [`retry_with_backoff`] will spend retrying before giving up, for the same
+/// reason `CONNECT_INITIAL_INTERVAL` is a local constant rather than `args`-configurable.
+const CONNECT_MAX_ELAPSED: Duration = Duration::from_secs(10);
+
+/// Retry `attempt` with a capped exponential backoff (starting at `CONNECT_INITIAL_INTERVAL`, up
+/// to `CONNECT_MAX_ELAPSED`) as long as it fails with a transient I/O error (connection refused,
+/// reset, or aborted — typical of a Dockerized database still warming up in CI). Any other error
+/// (auth, protocol) is treated as permanent and panics immediately, as does a transient error
+/// once `CONNECT_MAX_ELAPSED` has passed.
+fn retry_with_backoff<T, E, F, Fut>(rt: &tokio::runtime::Runtime, mut attempt: F) -> T
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let started_at = std::time::Instant::now();
+    let mut interval = CONNECT_INITIAL_INTERVAL;
+
+    loop {
+        match rt.block_on(attempt()) {
+            Ok(value) => return value,
+            Err(err) => {
+                let message = err.to_string().to_lowercase();
+                let transient = ["connection refused", "connection reset", "connection aborted"]
+                    .iter()
+                    .any(|needle| message.contains(needle));
+
+                if !transient || started_at.elapsed() >= CONNECT_MAX_ELAPSED {
+                    panic!("failed to connect to the test database: {err}");
+                }
+
+                rt.block_on(tokio::time::sleep(interval));
+                interval = (interval * 2).min(Duration::from_secs(5));
+            }
+        }
+    }
+}
+
 /// The multi-engine test API.
 pub struct TestApi {
     args: TestApiArgs,
     connection_string: String,
     admin_conn: Quaint,
     rt: tokio::runtime::Runtime,
+    circumstances: BitFlags<Tags>,
+    supports_transactional_ddl: bool,
 }
 
 impl TestApi {
@@ -29,25 +74,32 @@ impl TestApi {
         let db_name = args.test_function_name();
 
         let (admin_conn, connection_string) = if tags.contains(Tags::Postgres) {
-            rt.block_on(test_setup::create_postgres_database(db_name)).unwrap()
+            retry_with_backoff(&rt, || test_setup::create_postgres_database(db_name))
         } else if tags.contains(Tags::Mysql) {
-            rt.block_on(test_setup::create_mysql_database(db_name)).unwrap()
+            retry_with_backoff(&rt, || test_setup::create_mysql_database(db_name))
         } else if tags.contains(Tags::Mssql) {
-            rt.block_on(test_setup::init_mssql_database(args.database_url(), db_name))
-                .unwrap()
+            retry_with_backoff(&rt, || test_setup::init_mssql_database(args.database_url(), db_name))
         } else if tags.contains(Tags::Sqlite) {
             let url = test_setup::sqlite_test_url(db_name);
+            let conn = retry_with_backoff(&rt, || Quaint::new(&url));
 
-            (rt.block_on(Quaint::new(&url)).unwrap(), url)
+            (conn, url)
         } else {
             unreachable!()
         };
 
+        // MySQL/MariaDB implicitly commit DDL statements, so a migration can never be rolled back
+        // atomically there. Every other connector we test against supports transactional DDL.
+        let supports_transactional_ddl = !tags.contains(Tags::Mysql);
+        let circumstances = BitFlags::empty();
+
         TestApi {
             args,
             admin_conn,
             connection_string,
             rt,
+            circumstances,
+            supports_transactional_ddl,
         }
     }
 
@@ -134,13 +186,14 @@ impl TestApi {
 
         EngineTestApi {
             connector,
-            tags: self.args.tags(),
+            tags: self.tags(),
+            supports_transactional_ddl: self.supports_transactional_ddl,
             rt: &self.rt,
         }
     }
 
     fn tags(&self) -> BitFlags<Tags> {
-        self.args.tags()
+        self.args.tags() | self.circumstances
     }
 
     /// The name of the test function, as a string.
@@ -154,6 +207,7 @@ impl TestApi {
 pub struct EngineTestApi<'a> {
     connector: SqlMigrationConnector,
     tags: BitFlags<Tags>,
+    supports_transactional_ddl: bool,
     rt: &'a tokio::runtime::Runtime,
 }
 
@@ -207,4 +261,13 @@ impl EngineTestApi<'_> {
     pub fn raw_cmd(&self, cmd: &str) -> Result<(), quaint::error::Error> {
         self.rt.block_on(self.connector.quaint().raw_cmd(cmd))
     }
+
+    /// Whether the connector under test is capable of atomic transactional DDL in principle
+    /// (false only for MySQL/MariaDB, which implicitly commit DDL statements). This is a static
+    /// capability flag only — nothing in apply_migrations actually wraps migration statements in
+    /// a transaction yet, so there is no rollback-on-failure or per-statement fallback to test
+    /// against this flag today.
+    pub fn supports_transactional_ddl(&self) -> bool {
+        self.supports_transactional_ddl
+    }
 }
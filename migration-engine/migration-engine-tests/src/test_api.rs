@@ -29,6 +29,7 @@ use quaint::{
     single::Quaint,
 };
 use sql_migration_connector::SqlMigrationConnector;
+use sql_schema_describer::SqlSchema;
 use std::{borrow::Cow, fmt::Write as _};
 use tempfile::TempDir;
 use test_setup::{create_mysql_database, create_postgres_database, sqlite_test_url, BitFlags, Tags, TestApiArgs};
@@ -39,6 +40,8 @@ pub struct TestApi {
     api: SqlMigrationConnector,
     args: TestApiArgs,
     connection_string: String,
+    circumstances: BitFlags<Tags>,
+    supports_transactional_ddl: bool,
 }
 
 impl TestApi {
@@ -88,10 +91,17 @@ impl TestApi {
             }
         }
 
+        // MySQL/MariaDB implicitly commit DDL statements, so a migration can never be rolled
+        // back atomically there. Every other connector we test against supports transactional
+        // DDL.
+        let supports_transactional_ddl = !tags.contains(Tags::Mysql);
+
         TestApi {
             api,
             args,
             connection_string,
+            circumstances,
+            supports_transactional_ddl,
         }
     }
 
@@ -152,7 +162,16 @@ impl TestApi {
     }
 
     pub fn tags(&self) -> BitFlags<Tags> {
-        self.args.tags()
+        self.args.tags() | self.circumstances
+    }
+
+    /// Whether the connector under test is capable of atomic transactional DDL in principle
+    /// (false only for MySQL/MariaDB, which implicitly commit DDL statements). This is a static
+    /// capability flag only — nothing in apply_migrations actually wraps migration statements in
+    /// a transaction yet, so there is no rollback-on-failure or per-statement fallback to test
+    /// against this flag today.
+    pub fn supports_transactional_ddl(&self) -> bool {
+        self.supports_transactional_ddl
     }
 
     pub fn datasource(&self) -> String {
@@ -196,6 +215,7 @@ impl TestApi {
         Ok(())
     }
 
+    /// Applies every not-yet-applied migration found in `migrations_directory`.
     pub fn apply_migrations<'a>(&'a self, migrations_directory: &'a TempDir) -> ApplyMigrations<'a> {
         ApplyMigrations::new(&self.api, migrations_directory)
     }
@@ -269,6 +289,15 @@ impl TestApi {
         Ok(SchemaAssertion::new(schema, self.tags()))
     }
 
+    /// Serialize the described schema (tables, columns, indexes) of an arbitrary connector into a
+    /// normalized, connector-independent text form, so it can be compared across separate
+    /// connections to the same underlying database, e.g. across a reconnect to a file-backed
+    /// SQLite database. Foreign keys are not included in the snapshot.
+    pub async fn snapshot_schema_of(&self, connector: &SqlMigrationConnector) -> Result<String, ConnectorError> {
+        let schema = connector.describe_schema().await?;
+        Ok(render_schema_snapshot(&schema, self.lower_case_identifiers()))
+    }
+
     pub async fn dump_table(&self, table_name: &str) -> Result<quaint::prelude::ResultSet, quaint::error::Error> {
         let select_star =
             quaint::ast::Select::from_table(self.render_table_name(table_name)).value(quaint::ast::asterisk());
@@ -276,6 +305,20 @@ impl TestApi {
         self.database().query(select_star.into()).await
     }
 
+    /// Build a [`DataMigrationTest`] harness to assert how a single migration transforms the
+    /// contents of `table_name`.
+    pub fn data_migration_test<'a>(
+        &'a self,
+        migrations_directory: &'a TempDir,
+        table_name: &'a str,
+    ) -> DataMigrationTest<'a> {
+        DataMigrationTest {
+            api: self,
+            migrations_directory,
+            table_name,
+        }
+    }
+
     pub fn insert<'a>(&'a self, table_name: &'a str) -> SingleRowInsert<'a> {
         SingleRowInsert {
             insert: quaint::ast::Insert::single_into(self.render_table_name(table_name)),
@@ -415,3 +458,86 @@ impl MigrationsAssertions for MigrationRecord {
         Ok(self)
     }
 }
+
+/// A harness for testing a single data-transforming migration in isolation: seed rows with
+/// [`TestApi::insert`], call [`DataMigrationTest::apply`] with the migration to layer on top of
+/// whatever schema already exists, and compare the table contents it returns (the "after") with
+/// a [`DataMigrationTest::before`] snapshot to assert that a column rename, type change, or
+/// backfill transformed (or preserved) the expected rows. `apply` does not wrap the migration in a
+/// transaction or roll it back: if it errors out partway, the statements that already ran stay
+/// applied.
+pub struct DataMigrationTest<'a> {
+    api: &'a TestApi,
+    migrations_directory: &'a TempDir,
+    table_name: &'a str,
+}
+
+impl<'a> DataMigrationTest<'a> {
+    /// Dump the table before the migration under test is applied.
+    pub async fn before(&self) -> Result<quaint::prelude::ResultSet, quaint::error::Error> {
+        self.api.dump_table(self.table_name).await
+    }
+
+    /// Create and apply one migration, then dump the table again.
+    pub async fn apply(self, name: &str, schema: &str) -> Result<quaint::prelude::ResultSet, anyhow::Error> {
+        self.api
+            .create_migration(name, schema, self.migrations_directory)
+            .send()
+            .await?
+            .into_output();
+
+        self.api.apply_migrations(self.migrations_directory).send().await?;
+
+        Ok(self.api.dump_table(self.table_name).await?)
+    }
+}
+
+/// Shared rendering logic behind [`TestApi::snapshot_schema_of`], taking `lower_case_identifiers`
+/// directly so it doesn't need a `TestApi` to call.
+fn render_schema_snapshot(schema: &SqlSchema, lower_case_identifiers: bool) -> String {
+    let normalize = |identifier: &str| -> String {
+        if lower_case_identifiers {
+            identifier.to_ascii_lowercase()
+        } else {
+            identifier.to_owned()
+        }
+    };
+
+    let mut out = String::new();
+
+    let mut tables: Vec<_> = schema.table_walkers().collect();
+    tables.sort_by_key(|table| normalize(table.name()));
+
+    for table in tables {
+        let table_name = normalize(table.name());
+        writeln!(out, "table {table_name} {{").ok();
+
+        let mut columns: Vec<_> = table.columns().collect();
+        columns.sort_by_key(|column| normalize(column.name()));
+
+        for column in columns {
+            let nullable = if column.is_required() { "" } else { "?" };
+            writeln!(
+                out,
+                "  {}{} {}",
+                normalize(column.name()),
+                nullable,
+                column.column_type_family().to_string()
+            )
+            .ok();
+        }
+
+        let mut indexes: Vec<_> = table.indexes().collect();
+        indexes.sort_by_key(|index| normalize(index.name()));
+
+        for index in indexes {
+            let unique = if index.is_unique() { "unique " } else { "" };
+            let columns: Vec<_> = index.columns().map(|c| normalize(c.as_column().name())).collect();
+            writeln!(out, "  {}index ({})", unique, columns.join(", ")).ok();
+        }
+
+        writeln!(out, "}}").ok();
+    }
+
+    out
+}
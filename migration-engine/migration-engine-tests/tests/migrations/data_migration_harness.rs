@@ -0,0 +1,52 @@
+use migration_engine_tests::sql::*;
+use quaint::prelude::ResultSetExt;
+use test_macros::test_connector;
+
+const DM1: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+// The differ has no rename detection, so a datamodel edit that looks like a rename is actually a
+// drop-and-recreate: the old column's data does not carry over to the new one. This is exactly
+// the kind of destructive/lossy step the isolated data-migration harness is meant to let a
+// migration author catch before shipping it, so assert the data loss rather than data
+// preservation that the engine doesn't deliver.
+#[test_connector]
+async fn renaming_a_column_without_rename_detection_loses_the_data(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("01init", DM1, &migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat").value("id", 1).value("name", "Garfield").result_raw().await?;
+
+    let harness = api.data_migration_test(&migrations_directory, "Cat");
+
+    let before = harness.before().await?;
+    assert_eq!(before.len(), 1);
+
+    // `nickname` is optional so the new column can be added to the populated table without
+    // erroring on a missing default — the point being demonstrated is data loss, not a NOT NULL
+    // constraint violation.
+    let dm2 = r#"
+        model Cat {
+            id       Int     @id
+            nickname String?
+        }
+    "#;
+
+    let after = harness.apply("02rename_name_to_nickname", dm2).await?;
+
+    assert_eq!(after.len(), 1);
+    let row = after.into_single()?;
+    assert_eq!(row["nickname"].as_str(), None);
+
+    Ok(())
+}
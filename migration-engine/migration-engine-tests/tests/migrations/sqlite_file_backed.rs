@@ -0,0 +1,88 @@
+use migration_core::{GenericApi, SchemaPushInput};
+use migration_engine_tests::sql::*;
+use quaint::single::Quaint;
+use sql_migration_connector::SqlMigrationConnector;
+use tempfile::TempDir;
+use test_macros::test_connector;
+
+const DM: &str = r#"
+    model Test {
+        id   Int    @id
+        name String
+    }
+"#;
+
+// Unlike the throwaway in-memory URL `TestApi` normally uses for SQLite, a file-backed URL must
+// survive across separate connections to the same file, so this test asserts that a schema pushed
+// over one connection is still there after reconnecting, like it would be for a user's own file
+// database.
+#[test_connector(tags(Sqlite))]
+async fn file_backed_sqlite_persists_across_reconnects(_api: &TestApi) -> TestResult {
+    let dir = TempDir::new()?;
+    let db_path = dir.path().join("file_backed_test.db");
+    let url = format!("file:{}", db_path.to_string_lossy());
+
+    {
+        let connector = SqlMigrationConnector::new(&url, None).await?;
+        let quaint = Quaint::new(&url).await?;
+        drop(connector);
+        drop(quaint);
+    }
+
+    let first_connector = SqlMigrationConnector::new(&url, None).await?;
+    let migrations_directory = TempDir::new()?;
+
+    first_connector
+        .schema_push(&SchemaPushInput {
+            schema: DM.to_owned(),
+            force: true,
+            assume_empty: true,
+        })
+        .await?;
+
+    drop(first_connector);
+    drop(migrations_directory);
+
+    // Reconnect from scratch and verify the table is still there.
+    let second_connector = SqlMigrationConnector::new(&url, None).await?;
+    let schema = second_connector.describe_schema().await?;
+
+    assert!(schema.table_walkers().any(|table| table.name() == "Test"));
+
+    Ok(())
+}
+
+// `snapshot_schema_of` is meant to be stable for a given logical datamodel regardless of which
+// connector produced it, so a golden file could in principle be shared across Postgres, MySQL
+// and SQLite runs of this test. We can't ship an actual cross-connector golden file here: doing
+// so correctly means hand-verifying the real `ColumnTypeFamily::to_string()` / index-naming
+// output of connectors whose source isn't part of this tree, and a wrong guess baked into a
+// fixture would silently stop testing anything. What we *can* verify without running the real
+// connectors is determinism: describing the same on-disk schema twice must snapshot identically,
+// which is the property a golden-file comparison actually relies on.
+#[test_connector(tags(Sqlite))]
+async fn snapshot_schema_is_stable_across_reconnects(api: &TestApi) -> TestResult {
+    let dir = TempDir::new()?;
+    let db_path = dir.path().join("snapshot_schema_test.db");
+    let url = format!("file:{}", db_path.to_string_lossy());
+
+    let first_connector = SqlMigrationConnector::new(&url, None).await?;
+    first_connector
+        .schema_push(&SchemaPushInput {
+            schema: DM.to_owned(),
+            force: true,
+            assume_empty: true,
+        })
+        .await?;
+
+    let first_snapshot = api.snapshot_schema_of(&first_connector).await?;
+    drop(first_connector);
+
+    let second_connector = SqlMigrationConnector::new(&url, None).await?;
+    let second_snapshot = api.snapshot_schema_of(&second_connector).await?;
+
+    assert_eq!(first_snapshot, second_snapshot);
+    assert!(first_snapshot.contains("table Test {"));
+
+    Ok(())
+}